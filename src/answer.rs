@@ -1,5 +1,8 @@
+use crate::context::Context;
+use crate::errors::MathError;
 use crate::num::Num;
 use crate::opers::Calculation;
+use std::collections::HashMap;
 use std::fmt;
 use serde::{Serialize, Deserialize};
 
@@ -15,6 +18,9 @@ pub enum Answer<N: Num> {
 	Single(N),
 	/// Multiple answers. Will always be at least two (probably)
 	Multiple(Vec<N>),
+	/// A probability distribution over outcomes, e.g. the result of a dice roll (`2d6 + 3`).
+	/// The probabilities always sum to 1.0.
+	Distribution(Vec<(N, f64)>),
 }
 
 impl<N: Num> Answer<N> {
@@ -26,9 +32,16 @@ impl<N: Num> Answer<N> {
 				Answer::Multiple(ns) => for n in ns {
 					list.push(n)
 				},
+				Answer::Distribution(ds) => for (n, _) in ds {
+					list.push(n)
+				},
 			}
 		}
 
+		if self.is_distribution() || other.is_distribution() {
+			return self.op_distribution(other, oper);
+		}
+
 		match *self {
 			Answer::Single(ref n) => match *other {
 				Answer::Single(ref n2) => oper(n, n2),
@@ -39,6 +52,7 @@ impl<N: Num> Answer<N> {
 					}
 					Ok(Answer::Multiple(answers))
 				}
+				Answer::Distribution(_) => unreachable!(),
 			},
 			Answer::Multiple(ref ns) => match *other {
 				Answer::Single(ref n2) => {
@@ -57,7 +71,122 @@ impl<N: Num> Answer<N> {
 					}
 					Ok(Answer::Multiple(answers))
 				}
+				Answer::Distribution(_) => unreachable!(),
 			},
+			Answer::Distribution(_) => unreachable!(),
+		}
+	}
+
+	/// Combine two answers where at least one is a `Distribution`: take the Cartesian product of
+	/// outcomes, apply `oper` to each value pair, multiply the two probabilities, and accumulate
+	/// results keyed by the resulting value (equal outcomes have their probabilities summed).
+	/// The result is renormalized so its probabilities sum to 1.0.
+	///
+	/// `oper` isn't guaranteed to return a single value — `±` on two plain numbers, or a `sqrt`/
+	/// `pow` that fans out over roots, yields an `Answer::Multiple`. Each of those branches is
+	/// treated as equally likely and splits the pair's combined probability between them, rather
+	/// than assuming (and panicking on) a single outcome per pair.
+	///
+	/// Accumulates into a `HashMap<N, f64>` (`N: Eq + Hash`) rather than scanning a `Vec` per
+	/// insert: a linear scan here turns `dice`'s repeated folding into polynomial blowup (each of
+	/// `count` folds re-scans an outcome list that itself grows with `count * sides`), to the
+	/// point that a few dozen dice stop finishing in reasonable time.
+	fn op_distribution<F: Fn(&N, &N) -> Calculation<N>>(&self, other: &Self, oper: F) -> Calculation<N> {
+		let lhs = self.clone().into_distribution();
+		let rhs = other.clone().into_distribution();
+
+		let mut outcomes: HashMap<N, f64> = HashMap::new();
+		for (n, p) in &lhs {
+			for (n2, p2) in &rhs {
+				let values = oper(n, n2)?.to_vec();
+				let prob = (p * p2) / values.len() as f64;
+				for value in values {
+					*outcomes.entry(value).or_insert(0.0) += prob;
+				}
+			}
+		}
+
+		let total: f64 = outcomes.values().sum();
+		if total > 0.0 {
+			for p in outcomes.values_mut() {
+				*p /= total;
+			}
+		}
+
+		Ok(Answer::Distribution(outcomes.into_iter().collect()))
+	}
+
+	/// A bare die, `dM`: the uniform distribution over `1..=sides`.
+	pub fn die(sides: &N, ctx: &Context<N>) -> Calculation<N> {
+		Self::dice(&N::from_f64(1.0, ctx)?.unwrap_single(), sides, ctx)
+	}
+
+	/// Upper bound on `count` and `sides` in `dice`. `count` dice fold the single-die distribution
+	/// in one at a time and `sides` drives an up-front `Vec::with_capacity`, so neither is trusted
+	/// host config (like `ModInt`'s `fact_bound`) — both come straight from user expression text
+	/// and need a hard ceiling to keep a crafted `huge d huge` from hanging the evaluator or
+	/// attempting a huge allocation. Even with `op_distribution`'s O(1)-per-insert hash map
+	/// accumulation, folding in `count` dice one at a time over an outcome list that itself grows
+	/// with `count * sides` is still roughly `count^2 * sides^2` work, so the bound stays small
+	/// (tens, not thousands) rather than just guarding against u64 overflow.
+	const MAX_DICE_PARAM: u64 = 40;
+
+	/// `N d M`: rolls `count` `sides`-sided dice and sums them, producing the distribution of the
+	/// total. A single die is a uniform distribution over `1..=sides`; `count` dice is the
+	/// `count`-fold convolution of that distribution with itself (each additional die is combined
+	/// in via `op_distribution` using addition, so equal totals accumulate their probabilities).
+	pub fn dice(count: &N, sides: &N, ctx: &Context<N>) -> Calculation<N> {
+		let count = count.to_f64().round() as u64;
+		let sides = sides.to_f64().round() as u64;
+		if sides == 0 {
+			return Err(MathError::Other {
+				msg: String::from("a die must have at least one side"),
+			});
+		}
+		if count == 0 {
+			return Err(MathError::Other {
+				msg: String::from("must roll at least one die"),
+			});
+		}
+		if sides > Self::MAX_DICE_PARAM || count > Self::MAX_DICE_PARAM {
+			return Err(MathError::Other {
+				msg: format!("dice count and sides are each capped at {}", Self::MAX_DICE_PARAM),
+			});
+		}
+
+		let p = 1.0 / sides as f64;
+		let mut single_die = Vec::with_capacity(sides as usize);
+		for face in 1..=sides {
+			single_die.push((N::from_f64(face as f64, ctx)?.unwrap_single(), p));
+		}
+		let single_die = Answer::Distribution(single_die);
+
+		let mut total = single_die.clone();
+		for _ in 1..count {
+			total = total.op(&single_die, |a, b| a.add(b, ctx))?;
+		}
+		Ok(total)
+	}
+
+	/// Converts this answer into a distribution: a `Distribution` is returned as-is, a `Single`
+	/// becomes a single outcome with probability 1.0, and a `Multiple` is treated as a uniform
+	/// distribution over its values.
+	fn into_distribution(self) -> Vec<(N, f64)> {
+		match self {
+			Answer::Distribution(ds) => ds,
+			Answer::Single(n) => vec![(n, 1.0)],
+			Answer::Multiple(ns) => {
+				let p = 1.0 / ns.len() as f64;
+				ns.into_iter().map(|n| (n, p)).collect()
+			}
+		}
+	}
+
+	/// True if this answer is a `Distribution`
+	pub fn is_distribution(&self) -> bool {
+		match *self {
+			Answer::Distribution(_) => true,
+			_ => false,
 		}
 	}
 
@@ -69,6 +198,9 @@ impl<N: Num> Answer<N> {
 				Answer::Multiple(ns) => for n in ns {
 					list.push(n)
 				},
+				Answer::Distribution(ds) => for (n, _) in ds {
+					list.push(n)
+				},
 			}
 		}
 
@@ -81,6 +213,17 @@ impl<N: Num> Answer<N> {
 				}
 				Ok(Answer::Multiple(answers))
 			}
+			Answer::Distribution(ref ds) => {
+				let mut answers: HashMap<N, f64> = HashMap::new();
+				for (n, p) in ds {
+					let values = oper(n)?.to_vec();
+					let split = p / values.len() as f64;
+					for value in values {
+						*answers.entry(value).or_insert(0.0) += split;
+					}
+				}
+				Ok(Answer::Distribution(answers.into_iter().collect()))
+			}
 		}
 	}
 
@@ -89,6 +232,7 @@ impl<N: Num> Answer<N> {
 		match self {
 			Answer::Single(n) => n,
 			Answer::Multiple(_) => panic!("Attempted to unwrap multiple answers as one"),
+			Answer::Distribution(_) => panic!("Attempted to unwrap a distribution as one answer"),
 		}
 	}
 
@@ -97,6 +241,7 @@ impl<N: Num> Answer<N> {
 		match self {
 			Answer::Single(n) => vec![n],
 			Answer::Multiple(ns) => ns,
+			Answer::Distribution(ds) => ds.into_iter().map(|(n, _)| n).collect(),
 		}
 	}
 
@@ -110,6 +255,9 @@ impl<N: Num> Answer<N> {
 			Answer::Multiple(mut ns) => {
 				new.append(&mut ns);
 			}
+			Answer::Distribution(ds) => {
+				new.extend(ds.into_iter().map(|(n, _)| n));
+			}
 		}
 
 		match other {
@@ -119,11 +267,125 @@ impl<N: Num> Answer<N> {
 			Answer::Multiple(mut ns) => {
 				new.append(&mut ns);
 			}
+			Answer::Distribution(ds) => {
+				new.extend(ds.into_iter().map(|(n, _)| n));
+			}
 		}
 		Answer::Multiple(new)
 	}
 }
 
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::num::Rational;
+	use num_bigint::BigInt;
+	use num_rational::BigRational;
+
+	fn r(n: i64) -> Rational {
+		Rational(BigRational::from_integer(BigInt::from(n)))
+	}
+
+	fn probabilities(answer: &Answer<Rational>) -> Vec<(Rational, f64)> {
+		match answer {
+			Answer::Distribution(ds) => ds.clone(),
+			_ => panic!("expected a distribution"),
+		}
+	}
+
+	#[test]
+	fn die_is_uniform_over_its_sides() {
+		let ctx = Context::default();
+		let d6 = Answer::die(&r(6), &ctx).unwrap();
+		let outcomes = probabilities(&d6);
+		assert_eq!(outcomes.len(), 6);
+		for (_, p) in &outcomes {
+			assert!((p - 1.0 / 6.0).abs() < 1e-9);
+		}
+		let total: f64 = outcomes.iter().map(|(_, p)| p).sum();
+		assert!((total - 1.0).abs() < 1e-9);
+	}
+
+	#[test]
+	fn dice_convolves_the_single_die_distribution() {
+		let ctx = Context::default();
+		let two_d6 = Answer::dice(&r(2), &r(6), &ctx).unwrap();
+		let outcomes = probabilities(&two_d6);
+		// Totals 2..=12, 11 distinct outcomes.
+		assert_eq!(outcomes.len(), 11);
+		let seven = outcomes.iter().find(|(n, _)| *n == r(7)).unwrap().1;
+		let two = outcomes.iter().find(|(n, _)| *n == r(2)).unwrap().1;
+		// 7 is the most likely total on 2d6 (6/36), 2 the least likely (1/36).
+		assert!(seven > two);
+		assert!((seven - 6.0 / 36.0).abs() < 1e-9);
+		let total: f64 = outcomes.iter().map(|(_, p)| p).sum();
+		assert!((total - 1.0).abs() < 1e-9);
+	}
+
+	#[test]
+	fn dice_rejects_zero_sides_or_count() {
+		let ctx = Context::default();
+		assert!(Answer::dice(&r(1), &r(0), &ctx).is_err());
+		assert!(Answer::dice(&r(0), &r(6), &ctx).is_err());
+	}
+
+	#[test]
+	fn dice_rejects_oversized_count_or_sides() {
+		let ctx = Context::default();
+		assert!(Answer::dice(&r(1), &r(1_000_000_000), &ctx).is_err());
+		assert!(Answer::dice(&r(1_000_000_000), &r(6), &ctx).is_err());
+		// Just over the cap on either side fails...
+		assert!(Answer::dice(&r(1), &r(Answer::<Rational>::MAX_DICE_PARAM as i64 + 1), &ctx).is_err());
+		// ...while the cap itself is still a valid roll.
+		assert!(Answer::dice(&r(1), &r(Answer::<Rational>::MAX_DICE_PARAM as i64), &ctx).is_ok());
+	}
+
+	/// `MAX_DICE_PARAM d MAX_DICE_PARAM` (the largest roll the cap allows) must complete quickly.
+	/// This is the case the cap alone doesn't guarantee: with a `Vec` + linear `.find()`
+	/// accumulation, folding in dice one at a time would be roughly `count^3 * sides^3` work. The
+	/// hash-map accumulation in `op_distribution` that this test exercises brings that down to
+	/// roughly `count^2 * sides^2`.
+	#[test]
+	fn dice_at_the_cap_completes_quickly() {
+		let ctx = Context::default();
+		let cap = Answer::<Rational>::MAX_DICE_PARAM as i64;
+		let start = std::time::Instant::now();
+		let result = Answer::dice(&r(cap), &r(cap), &ctx);
+		assert!(result.is_ok());
+		assert!(start.elapsed() < std::time::Duration::from_secs(5));
+	}
+
+	#[test]
+	fn op_distribution_splits_probability_over_multi_valued_results() {
+		let ctx = Context::default();
+		let d2 = Answer::die(&r(2), &ctx).unwrap();
+		// A fan-out operator: every pair produces both possible signs of the sum.
+		let fanned = d2
+			.op(&d2, |a, b| {
+				let sum = a.add(b, &ctx)?.unwrap_single();
+				Ok(Answer::Multiple(vec![sum.clone(), sum.neg(&ctx)?.unwrap_single()]))
+			})
+			.unwrap();
+		let outcomes = probabilities(&fanned);
+		let total: f64 = outcomes.iter().map(|(_, p)| p).sum();
+		assert!((total - 1.0).abs() < 1e-9);
+	}
+
+	#[test]
+	fn unop_splits_probability_over_multi_valued_results() {
+		let ctx = Context::default();
+		let d2 = Answer::die(&r(2), &ctx).unwrap();
+		let fanned = d2
+			.unop(|n| Ok(Answer::Multiple(vec![n.clone(), n.neg(&ctx)?.unwrap_single()])))
+			.unwrap();
+		let outcomes = probabilities(&fanned);
+		// Each of the 2 die faces splits into 2 signed outcomes, all distinct here.
+		assert_eq!(outcomes.len(), 4);
+		let total: f64 = outcomes.iter().map(|(_, p)| p).sum();
+		assert!((total - 1.0).abs() < 1e-9);
+	}
+}
+
 impl<N: Num> fmt::Display for Answer<N> {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		match *self {
@@ -139,6 +401,16 @@ impl<N: Num> fmt::Display for Answer<N> {
 				buf.push_str("}");
 				write!(f, "{}", &buf)
 			}
+			Answer::Distribution(ref ds) => {
+				let mut buf = String::new();
+				for (i, (n, p)) in ds.iter().enumerate() {
+					buf.push_str(&format!("{}: {}", n, p));
+					if i + 1 < ds.len() {
+						buf.push('\n');
+					}
+				}
+				write!(f, "{}", &buf)
+			}
 		}
 	}
 }
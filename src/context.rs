@@ -0,0 +1,27 @@
+use crate::num::Num;
+use std::collections::HashMap;
+
+/// Evaluation context threaded through a `Calculation`: variables, and any backend-specific
+/// configuration (e.g. the prime modulus for `ModInt`) that a particular `Num` impl needs in
+/// order to evaluate. Kept generic over `N` so each backend can stash its own config value under
+/// a well-known key rather than `Context` growing a field per backend.
+#[derive(Debug, Clone)]
+pub struct Context<N: Num> {
+	pub vars: HashMap<String, N>,
+	pub config: HashMap<String, N>,
+}
+
+impl<N: Num> Default for Context<N> {
+	fn default() -> Self {
+		Context {
+			vars: HashMap::new(),
+			config: HashMap::new(),
+		}
+	}
+}
+
+impl<N: Num> Context<N> {
+	pub fn new() -> Self {
+		Self::default()
+	}
+}
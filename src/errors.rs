@@ -0,0 +1,25 @@
+use std::fmt;
+
+/// An error encountered while evaluating an expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MathError {
+	/// Attempted to divide (or find a modular inverse) by a value that is zero, or zero modulo
+	/// the active modulus.
+	DivideByZero,
+	/// Attempted to take the square root of a negative number.
+	NegativeRoot,
+	/// A backend-specific error that doesn't fit the other variants.
+	Other { msg: String },
+}
+
+impl fmt::Display for MathError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			MathError::DivideByZero => write!(f, "attempted to divide by zero"),
+			MathError::NegativeRoot => write!(f, "attempted to take the root of a negative number"),
+			MathError::Other { ref msg } => write!(f, "{}", msg),
+		}
+	}
+}
+
+impl std::error::Error for MathError {}
@@ -0,0 +1,7 @@
+pub mod answer;
+pub mod context;
+pub mod errors;
+pub mod logic;
+pub mod num;
+pub mod op;
+pub mod opers;
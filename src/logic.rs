@@ -0,0 +1,90 @@
+use crate::context::Context;
+use crate::num::Num;
+use crate::op::In;
+use crate::opers::Calculation;
+use std::cmp::Ordering;
+
+/// Evaluates one of the `In::{Lt,Le,Gt,Ge,Eq,Ne}` comparison operators, backed by `Num::compare`.
+/// Values that `compare` reports as incomparable make every comparison but `Ne` false, matching
+/// the usual convention that unordered values are never equal and never `<`/`>` each other.
+///
+/// This crate has no tokenizer/parser of its own, so there's no built-in evaluator that dispatches
+/// `Op::In` onto these directly — callers wire it in themselves, matching `op` on a parsed
+/// expression's `Op::In` variant and calling `compare`/`connective`/`not` for the comparison and
+/// logical cases.
+pub fn compare<N: Num>(op: &In, a: &N, b: &N, ctx: &Context<N>) -> Calculation<N> {
+	let ord = a.compare(b);
+	let truth = match op {
+		In::Lt => ord == Some(Ordering::Less),
+		In::Le => ord == Some(Ordering::Less) || ord == Some(Ordering::Equal),
+		In::Gt => ord == Some(Ordering::Greater),
+		In::Ge => ord == Some(Ordering::Greater) || ord == Some(Ordering::Equal),
+		In::Eq => ord == Some(Ordering::Equal),
+		In::Ne => ord != Some(Ordering::Equal),
+		_ => unreachable!("compare() called with a non-comparison operator"),
+	};
+	N::from_bool(truth, ctx)
+}
+
+/// Evaluates one of the `In::{And,Or,Implies,Iff}` logical connectives, backed by `Num::is_truthy`
+/// and `Num::from_bool`.
+pub fn connective<N: Num>(op: &In, a: &N, b: &N, ctx: &Context<N>) -> Calculation<N> {
+	let (ta, tb) = (a.is_truthy(), b.is_truthy());
+	let truth = match op {
+		In::And => ta && tb,
+		In::Or => ta || tb,
+		In::Implies => !ta || tb,
+		In::Iff => ta == tb,
+		_ => unreachable!("connective() called with a non-logical operator"),
+	};
+	N::from_bool(truth, ctx)
+}
+
+/// Evaluates `Pre::Not`, backed by `Num::is_truthy`.
+pub fn not<N: Num>(a: &N, ctx: &Context<N>) -> Calculation<N> {
+	N::from_bool(!a.is_truthy(), ctx)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::num::Rational;
+	use num_bigint::BigInt;
+	use num_rational::BigRational;
+
+	fn r(n: i64) -> Rational {
+		Rational(BigRational::from_integer(BigInt::from(n)))
+	}
+
+	fn truthy(calc: Calculation<Rational>) -> bool {
+		calc.unwrap().unwrap_single().is_truthy()
+	}
+
+	#[test]
+	fn compare_operators() {
+		let ctx = Context::default();
+		assert!(truthy(compare(&In::Lt, &r(2), &r(3), &ctx)));
+		assert!(!truthy(compare(&In::Lt, &r(3), &r(3), &ctx)));
+		assert!(truthy(compare(&In::Le, &r(3), &r(3), &ctx)));
+		assert!(truthy(compare(&In::Eq, &r(3), &r(3), &ctx)));
+		assert!(truthy(compare(&In::Ne, &r(2), &r(3), &ctx)));
+	}
+
+	#[test]
+	fn connective_operators() {
+		let ctx = Context::default();
+		assert!(truthy(connective(&In::And, &r(1), &r(1), &ctx)));
+		assert!(!truthy(connective(&In::And, &r(1), &r(0), &ctx)));
+		assert!(truthy(connective(&In::Or, &r(0), &r(1), &ctx)));
+		assert!(truthy(connective(&In::Implies, &r(0), &r(0), &ctx)));
+		assert!(!truthy(connective(&In::Implies, &r(1), &r(0), &ctx)));
+		assert!(truthy(connective(&In::Iff, &r(0), &r(0), &ctx)));
+	}
+
+	#[test]
+	fn not_negates_truthiness() {
+		let ctx = Context::default();
+		assert!(!truthy(not(&r(1), &ctx)));
+		assert!(truthy(not(&r(0), &ctx)));
+	}
+}
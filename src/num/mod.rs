@@ -0,0 +1,66 @@
+use crate::context::Context;
+use crate::opers::Calculation;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+
+pub mod modular;
+pub mod rational;
+
+pub use self::modular::ModInt;
+pub use self::rational::Rational;
+
+/// A type that can be used as the numeric backend of a `Calculation`. Implementing this trait
+/// for a new type plugs it into the `Answer`/`Context`/`Calculation` machinery without any
+/// changes elsewhere. The backend is chosen by the type parameter `N` of `Context<N>`; this crate
+/// has no parser, so there is no parse-time switch between backends.
+///
+/// `Eq + Hash` lets `Answer::Distribution` accumulate outcome probabilities in a hash map keyed by
+/// value (equal outcomes, e.g. equal dice totals, must land in the same bucket) instead of a
+/// linear scan per insert.
+pub trait Num: Debug + Display + Clone + PartialEq + Eq + Hash + Serialize + DeserializeOwned + 'static {
+	fn from_f64(t: f64, ctx: &Context<Self>) -> Calculation<Self>
+	where
+		Self: Sized;
+
+	fn add(&self, other: &Self, ctx: &Context<Self>) -> Calculation<Self>
+	where
+		Self: Sized;
+	fn sub(&self, other: &Self, ctx: &Context<Self>) -> Calculation<Self>
+	where
+		Self: Sized;
+	fn mul(&self, other: &Self, ctx: &Context<Self>) -> Calculation<Self>
+	where
+		Self: Sized;
+	fn div(&self, other: &Self, ctx: &Context<Self>) -> Calculation<Self>
+	where
+		Self: Sized;
+	fn pow(&self, other: &Self, ctx: &Context<Self>) -> Calculation<Self>
+	where
+		Self: Sized;
+	/// The remainder of `self / other`, i.e. `self - other * floor(self / other)`. Returns
+	/// `MathError::DivideByZero` when `other` is zero.
+	fn rem(&self, other: &Self, ctx: &Context<Self>) -> Calculation<Self>
+	where
+		Self: Sized;
+	fn sqrt(&self, ctx: &Context<Self>) -> Calculation<Self>
+	where
+		Self: Sized;
+	fn neg(&self, ctx: &Context<Self>) -> Calculation<Self>
+	where
+		Self: Sized;
+
+	/// Orders two values, backing the `Lt`/`Le`/`Gt`/`Ge`/`Eq`/`Ne` comparison operators. `None`
+	/// means the two values aren't comparable.
+	fn compare(&self, other: &Self) -> Option<Ordering>;
+	/// Whether this value counts as "true" when used as a boolean (by `And`/`Or`/`Not`/...).
+	fn is_truthy(&self) -> bool;
+	/// The canonical truth value used as the result of comparisons and logical connectives.
+	fn from_bool(b: bool, ctx: &Context<Self>) -> Calculation<Self>
+	where
+		Self: Sized;
+
+	fn to_f64(&self) -> f64;
+}
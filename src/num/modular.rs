@@ -0,0 +1,424 @@
+use crate::answer::Answer;
+use crate::context::Context;
+use crate::errors::MathError;
+use crate::num::Num;
+use crate::opers::Calculation;
+use serde::{Serialize, Deserialize};
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// The well-known `Context` config key a `ModInt`'s prime modulus is read from. Carried on the
+/// context rather than on every value so a whole expression is evaluated under one modulus.
+const MODULUS_KEY: &str = "modulus";
+/// The well-known `Context` config key for the bound the factorial/inverse-factorial tables are
+/// precomputed up to (see `factorial`, `n_choose_r`, `n_permute_r`).
+const FACT_BOUND_KEY: &str = "fact_bound";
+
+/// A number reduced modulo a prime `p` carried on the evaluation `Context`. All arithmetic stays
+/// inside `0..p`; division is multiplication by the modular inverse (Fermat's little theorem:
+/// `x^(p-2) mod p`), so it's only defined when `p` is actually prime.
+///
+/// `exact` tracks the true (unreduced) integer magnitude alongside the canonical field element
+/// `val`, tallied with wrapping integer arithmetic through `add`/`sub`/`mul`/`neg`. It's signed
+/// (unlike `val`) so that a negative true value — e.g. the result of `neg` — stays recognizably
+/// negative instead of wrapping into a huge positive magnitude. `pow` reads the exponent from
+/// `exact` rather than `val`: the exponent of `base^e` is a plain integer exponent, not a field
+/// element, so reducing it mod `p` before exponentiating (as opposed to mod `p - 1`, per Fermat)
+/// would silently produce the wrong power for any `e >= p`; a negative `exact` routes through the
+/// modular inverse instead of being reinterpreted as a huge positive exponent. Operations with no
+/// well-defined "true magnitude" (`div`, `rem`, `pow`, `sqrt`) reset `exact` to the resulting
+/// `val`; using such a result as a further exponent falls back to that reduced value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModInt {
+	pub val: u64,
+	pub modulus: u64,
+	exact: i128,
+}
+
+impl PartialEq for ModInt {
+	/// `exact` is bookkeeping for `pow`, not part of the field element's identity: two values
+	/// with the same `val` under the same `modulus` are the same number regardless of how they
+	/// were computed.
+	fn eq(&self, other: &Self) -> bool {
+		self.val == other.val && self.modulus == other.modulus
+	}
+}
+
+impl Eq for ModInt {}
+
+impl Hash for ModInt {
+	/// Hashes the same fields `eq` compares (`val`, `modulus`), leaving out `exact` so that equal
+	/// values (by `PartialEq`) always land in the same bucket of a `HashMap<ModInt, _>`.
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.val.hash(state);
+		self.modulus.hash(state);
+	}
+}
+
+impl ModInt {
+	pub fn new(val: u64, modulus: u64) -> Self {
+		ModInt {
+			val: val % modulus,
+			modulus,
+			exact: val as i128,
+		}
+	}
+
+	fn with_exact(val: u64, exact: i128, modulus: u64) -> Self {
+		ModInt {
+			val: val % modulus,
+			modulus,
+			exact,
+		}
+	}
+
+	fn modulus_from_ctx(ctx: &Context<Self>) -> Result<u64, MathError> {
+		match ctx.config.get(MODULUS_KEY) {
+			Some(m) => Ok(m.modulus),
+			None => Err(MathError::Other {
+				msg: String::from("no modulus set on the context for the ModInt backend"),
+			}),
+		}
+	}
+
+	/// Binary exponentiation, `self.val ^ exp (mod modulus)`.
+	fn pow_u64(&self, mut exp: u64) -> ModInt {
+		let mut base = self.val % self.modulus;
+		let mut result = 1u64 % self.modulus;
+		while exp > 0 {
+			if exp & 1 == 1 {
+				result = (result as u128 * base as u128 % self.modulus as u128) as u64;
+			}
+			base = (base as u128 * base as u128 % self.modulus as u128) as u64;
+			exp >>= 1;
+		}
+		ModInt::new(result, self.modulus)
+	}
+
+	/// The modular inverse of `self` via Fermat's little theorem, valid when `modulus` is prime
+	/// and `self` isn't `0 mod modulus`.
+	fn inverse(&self) -> Calculation<ModInt> {
+		if self.val == 0 {
+			return Err(MathError::DivideByZero);
+		}
+		Ok(Answer::Single(self.pow_u64(self.modulus - 2)))
+	}
+}
+
+impl fmt::Display for ModInt {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}", self.val)
+	}
+}
+
+impl Num for ModInt {
+	fn from_f64(t: f64, ctx: &Context<Self>) -> Calculation<Self> {
+		if t.fract() != 0.0 {
+			return Err(MathError::Other {
+				msg: String::from("the modular-arithmetic backend only accepts integer literals"),
+			});
+		}
+		let modulus = Self::modulus_from_ctx(ctx)?;
+		// `t as u64` saturates negative floats to `0` instead of wrapping or erroring, so a
+		// negative literal has to be reduced by hand: widen to `i128` (`modulus` may be close to
+		// `u64::MAX`) and use `rem_euclid` to land in `0..modulus` regardless of sign.
+		let exact = t as i128;
+		let val = exact.rem_euclid(modulus as i128) as u64;
+		Ok(Answer::Single(ModInt::with_exact(val, exact, modulus)))
+	}
+
+	fn add(&self, other: &Self, _ctx: &Context<Self>) -> Calculation<Self> {
+		let val = (self.val as u128 + other.val as u128) % self.modulus as u128;
+		let exact = self.exact.wrapping_add(other.exact);
+		Ok(Answer::Single(ModInt::with_exact(val as u64, exact, self.modulus)))
+	}
+
+	fn sub(&self, other: &Self, _ctx: &Context<Self>) -> Calculation<Self> {
+		let val = (self.val as u128 + self.modulus as u128 - other.val as u128) % self.modulus as u128;
+		let exact = self.exact.wrapping_sub(other.exact);
+		Ok(Answer::Single(ModInt::with_exact(val as u64, exact, self.modulus)))
+	}
+
+	fn mul(&self, other: &Self, _ctx: &Context<Self>) -> Calculation<Self> {
+		let val = self.val as u128 * other.val as u128 % self.modulus as u128;
+		let exact = self.exact.wrapping_mul(other.exact);
+		Ok(Answer::Single(ModInt::with_exact(val as u64, exact, self.modulus)))
+	}
+
+	fn div(&self, other: &Self, ctx: &Context<Self>) -> Calculation<Self> {
+		let inv = other.inverse()?.unwrap_single();
+		let quotient = self.mul(&inv, ctx)?.unwrap_single();
+		Ok(Answer::Single(ModInt::new(quotient.val, quotient.modulus)))
+	}
+
+	/// Exponentiation-by-squaring on the exponent, reduced mod `p` at every step. Reads the
+	/// exponent from `other.exact` (the true integer magnitude), not `other.val` (the field
+	/// element reduced mod `p`) — see the type-level doc comment for why that distinction
+	/// matters. A negative `exact` (e.g. from `neg`) is routed through `inverse()` rather than
+	/// reinterpreted as a huge positive exponent.
+	fn pow(&self, other: &Self, _ctx: &Context<Self>) -> Calculation<Self> {
+		if other.exact < 0 {
+			let magnitude = other.exact.unsigned_abs() as u64;
+			let inv = self.inverse()?.unwrap_single();
+			return Ok(Answer::Single(inv.pow_u64(magnitude)));
+		}
+		Ok(Answer::Single(self.pow_u64(other.exact as u64)))
+	}
+
+	/// `self - other * floor(self / other)` on the canonical representatives in `0..modulus`.
+	fn rem(&self, other: &Self, _ctx: &Context<Self>) -> Calculation<Self> {
+		if other.val == 0 {
+			return Err(MathError::DivideByZero);
+		}
+		Ok(Answer::Single(ModInt::new(self.val % other.val, self.modulus)))
+	}
+
+	fn sqrt(&self, _ctx: &Context<Self>) -> Calculation<Self> {
+		Err(MathError::Other {
+			msg: String::from("sqrt is not supported by the modular-arithmetic backend"),
+		})
+	}
+
+	fn neg(&self, _ctx: &Context<Self>) -> Calculation<Self> {
+		let exact = self.exact.wrapping_neg();
+		Ok(Answer::Single(ModInt::with_exact(self.modulus - self.val, exact, self.modulus)))
+	}
+
+	/// Compares the canonical representatives in `0..modulus`. This is an ordering of
+	/// representatives, not a mathematically meaningful order on the field itself.
+	fn compare(&self, other: &Self) -> Option<Ordering> {
+		self.val.partial_cmp(&other.val)
+	}
+
+	fn is_truthy(&self) -> bool {
+		self.val != 0
+	}
+
+	fn from_bool(b: bool, ctx: &Context<Self>) -> Calculation<Self> {
+		let modulus = Self::modulus_from_ctx(ctx)?;
+		Ok(Answer::Single(ModInt::new(if b { 1 } else { 0 }, modulus)))
+	}
+
+	fn to_f64(&self) -> f64 {
+		self.val as f64
+	}
+}
+
+/// Precomputed factorial and inverse-factorial tables mod `p`, up to some bound, rebuilt whenever
+/// `factorial`/`n_choose_r`/`n_permute_r` are called. Once `i!` is a multiple of `p` it has no
+/// modular inverse, so the table is clamped to `0!..=(p - 1)!`; lookups past that (or past the
+/// context's own bound) fail with a `MathError` instead of indexing out of range.
+///
+/// All of this assumes `p` is actually prime, same as `div`/`pow`'s use of Fermat's little
+/// theorem elsewhere in this module — nothing validates that up front. A composite modulus can
+/// still make `fact[bound]` itself `0 mod p` (e.g. `3! = 0 mod 6`), in which case `build_tables`
+/// reports a `MathError` instead of letting that propagate into an `.expect()`. A nonzero zero
+/// divisor (e.g. `2! = 2 mod 4`) is not detected, and the inverse tables are then not valid.
+struct FactorialTables {
+	fact: Vec<u64>,
+	inv_fact: Vec<u64>,
+}
+
+fn build_tables(modulus: u64, bound: u64) -> Result<FactorialTables, MathError> {
+	let bound = (bound.min(modulus.saturating_sub(1))) as usize;
+	let mut fact = vec![1u64; bound + 1];
+	for i in 1..=bound {
+		fact[i] = (fact[i - 1] as u128 * i as u128 % modulus as u128) as u64;
+	}
+	let mut inv_fact = vec![1u64; bound + 1];
+	inv_fact[bound] = ModInt::new(fact[bound], modulus)
+		.inverse()
+		.map_err(|_| MathError::Other {
+			msg: format!(
+				"cannot build factorial tables mod {}: {}! is a zero divisor mod {}, which means the modulus isn't prime",
+				modulus, bound, modulus
+			),
+		})?
+		.unwrap_single()
+		.val;
+	for i in (0..bound).rev() {
+		inv_fact[i] = (inv_fact[i + 1] as u128 * (i as u128 + 1) % modulus as u128) as u64;
+	}
+	Ok(FactorialTables { fact, inv_fact })
+}
+
+fn table_lookup(table: &[u64], idx: u64, modulus: u64) -> Calculation<ModInt> {
+	match table.get(idx as usize) {
+		Some(&val) => Ok(Answer::Single(ModInt::new(val, modulus))),
+		None => Err(MathError::Other {
+			msg: format!("{} is beyond the configured factorial table bound", idx),
+		}),
+	}
+}
+
+fn tables_for(ctx: &Context<ModInt>) -> Result<FactorialTables, MathError> {
+	let modulus = ModInt::modulus_from_ctx(ctx)?;
+	let bound = ctx
+		.config
+		.get(FACT_BOUND_KEY)
+		.map(|b| b.val)
+		.unwrap_or(0);
+	build_tables(modulus, bound)
+}
+
+/// `n!` mod the context's modulus, via the precomputed factorial table. Backs the postfix
+/// `Post::Fact` operator for the `ModInt` backend.
+pub fn factorial(n: &ModInt, ctx: &Context<ModInt>) -> Calculation<ModInt> {
+	let tables = tables_for(ctx)?;
+	table_lookup(&tables.fact, n.val, n.modulus)
+}
+
+/// `nCr` (the binomial coefficient `n` choose `r`) mod the context's modulus.
+pub fn n_choose_r(n: &ModInt, r: &ModInt, ctx: &Context<ModInt>) -> Calculation<ModInt> {
+	if r.val > n.val {
+		return Ok(Answer::Single(ModInt::new(0, n.modulus)));
+	}
+	let tables = tables_for(ctx)?;
+	let n_fact = table_lookup(&tables.fact, n.val, n.modulus)?.unwrap_single().val as u128;
+	let inv_r = table_lookup(&tables.inv_fact, r.val, n.modulus)?.unwrap_single().val as u128;
+	let inv_nr = table_lookup(&tables.inv_fact, n.val - r.val, n.modulus)?.unwrap_single().val as u128;
+	let result = n_fact * inv_r % n.modulus as u128 * inv_nr % n.modulus as u128;
+	Ok(Answer::Single(ModInt::new(result as u64, n.modulus)))
+}
+
+/// `nPr` (the number of ways to arrange `r` of `n` items) mod the context's modulus.
+pub fn n_permute_r(n: &ModInt, r: &ModInt, ctx: &Context<ModInt>) -> Calculation<ModInt> {
+	if r.val > n.val {
+		return Ok(Answer::Single(ModInt::new(0, n.modulus)));
+	}
+	let tables = tables_for(ctx)?;
+	let n_fact = table_lookup(&tables.fact, n.val, n.modulus)?.unwrap_single().val as u128;
+	let inv_nr = table_lookup(&tables.inv_fact, n.val - r.val, n.modulus)?.unwrap_single().val as u128;
+	let result = n_fact * inv_nr % n.modulus as u128;
+	Ok(Answer::Single(ModInt::new(result as u64, n.modulus)))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn rem_matches_floored_division() {
+		let ctx = Context::default();
+		let a = ModInt::new(17, 1_000_000_007);
+		let b = ModInt::new(5, 1_000_000_007);
+		assert_eq!(a.rem(&b, &ctx).unwrap().unwrap_single(), ModInt::new(2, 1_000_000_007));
+	}
+
+	#[test]
+	fn rem_by_zero_is_an_error() {
+		let ctx = Context::default();
+		let a = ModInt::new(17, 1_000_000_007);
+		let zero = ModInt::new(0, 1_000_000_007);
+		assert_eq!(a.rem(&zero, &ctx), Err(MathError::DivideByZero));
+	}
+
+	/// `2^11 mod 11` must use the true exponent `11`, not `11 mod 11 = 0` — Fermat only lets the
+	/// exponent be reduced mod `p - 1`, not `p`. Reducing it mod `p` (the bug this guards against)
+	/// would compute `2^0 = 1` instead of the correct `2`.
+	#[test]
+	fn pow_uses_the_true_exponent_not_the_field_element() {
+		let ctx = Context::default();
+		let base = ModInt::new(2, 11);
+		let exp = ModInt::new(11, 11);
+		assert_eq!(base.pow(&exp, &ctx).unwrap().unwrap_single(), ModInt::new(2, 11));
+	}
+
+	/// `2^(-1) mod 11` must equal `2`'s modular inverse (`6`, since `2*6 = 12 = 1 mod 11`), not
+	/// `2^(u64::MAX)` from misreading the negated exponent's wrapped magnitude as a huge positive
+	/// power.
+	#[test]
+	fn pow_with_negative_exponent_uses_the_inverse() {
+		let ctx = Context::default();
+		let base = ModInt::new(2, 11);
+		let neg_one = ModInt::new(1, 11).neg(&ctx).unwrap().unwrap_single();
+		assert_eq!(base.pow(&neg_one, &ctx).unwrap().unwrap_single(), ModInt::new(6, 11));
+	}
+
+	#[test]
+	fn add_and_sub_dont_overflow_near_u64_max() {
+		let ctx = Context::default();
+		let modulus: u64 = 18_446_744_073_709_551_557;
+		let a = ModInt::new(modulus - 1, modulus);
+		let b = ModInt::new(modulus - 1, modulus);
+		assert_eq!(a.add(&b, &ctx).unwrap().unwrap_single(), ModInt::new(modulus - 2, modulus));
+
+		let zero = ModInt::new(0, modulus);
+		let one_less = ModInt::new(modulus - 1, modulus);
+		assert_eq!(zero.sub(&one_less, &ctx).unwrap().unwrap_single(), ModInt::new(1, modulus));
+	}
+
+	#[test]
+	fn inverse_and_division() {
+		let ctx = Context::default();
+		let a = ModInt::new(4, 11);
+		let b = ModInt::new(3, 11);
+		// 4 / 3 mod 11: 3's inverse mod 11 is 4 (3*4 = 12 = 1 mod 11), so 4 * 4 = 16 = 5 mod 11.
+		assert_eq!(a.div(&b, &ctx).unwrap().unwrap_single(), ModInt::new(5, 11));
+	}
+
+	#[test]
+	fn division_by_zero_is_an_error() {
+		let ctx = Context::default();
+		let a = ModInt::new(4, 11);
+		let zero = ModInt::new(0, 11);
+		assert_eq!(a.div(&zero, &ctx), Err(MathError::DivideByZero));
+	}
+
+	#[test]
+	fn from_f64_reduces_negative_literals() {
+		let mut ctx = Context::default();
+		ctx.config.insert(MODULUS_KEY.to_string(), ModInt::new(0, 11));
+		// -5 mod 11 = 6, not 0 (`(-5.0_f64) as u64` saturates to 0).
+		assert_eq!(ModInt::from_f64(-5.0, &ctx).unwrap().unwrap_single(), ModInt::new(6, 11));
+	}
+
+	#[test]
+	fn from_f64_rejects_non_integer_literals() {
+		let mut ctx = Context::default();
+		ctx.config.insert(MODULUS_KEY.to_string(), ModInt::new(0, 11));
+		assert!(ModInt::from_f64(1.5, &ctx).is_err());
+	}
+
+	fn ctx_with_modulus_and_bound(modulus: u64, bound: u64) -> Context<ModInt> {
+		let mut ctx = Context::default();
+		ctx.config.insert(MODULUS_KEY.to_string(), ModInt::new(0, modulus));
+		ctx.config.insert(FACT_BOUND_KEY.to_string(), ModInt::new(bound, modulus));
+		ctx
+	}
+
+	#[test]
+	fn factorial_matches_direct_computation() {
+		let ctx = ctx_with_modulus_and_bound(1_000_000_007, 10);
+		let five = ModInt::new(5, 1_000_000_007);
+		assert_eq!(factorial(&five, &ctx).unwrap().unwrap_single(), ModInt::new(120, 1_000_000_007));
+	}
+
+	#[test]
+	fn n_choose_r_matches_direct_computation() {
+		let ctx = ctx_with_modulus_and_bound(1_000_000_007, 10);
+		let n = ModInt::new(5, 1_000_000_007);
+		let r = ModInt::new(2, 1_000_000_007);
+		// C(5, 2) = 10
+		assert_eq!(n_choose_r(&n, &r, &ctx).unwrap().unwrap_single(), ModInt::new(10, 1_000_000_007));
+	}
+
+	#[test]
+	fn n_permute_r_matches_direct_computation() {
+		let ctx = ctx_with_modulus_and_bound(1_000_000_007, 10);
+		let n = ModInt::new(5, 1_000_000_007);
+		let r = ModInt::new(2, 1_000_000_007);
+		// P(5, 2) = 20
+		assert_eq!(n_permute_r(&n, &r, &ctx).unwrap().unwrap_single(), ModInt::new(20, 1_000_000_007));
+	}
+
+	/// A composite modulus can make `bound!` itself a zero divisor (`3! = 0 mod 6`), which has no
+	/// modular inverse. `build_tables` must report that as a `MathError`, not panic.
+	#[test]
+	fn factorial_under_a_composite_modulus_is_an_error_not_a_panic() {
+		let ctx = ctx_with_modulus_and_bound(6, 3);
+		let three = ModInt::new(3, 6);
+		assert!(factorial(&three, &ctx).is_err());
+	}
+}
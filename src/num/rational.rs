@@ -0,0 +1,223 @@
+use crate::answer::Answer;
+use crate::context::Context;
+use crate::errors::MathError;
+use crate::num::Num;
+use crate::opers::Calculation;
+use num_bigint::BigInt;
+use num_integer::Roots;
+use num_rational::BigRational;
+use num_traits::{Signed, ToPrimitive, Zero};
+use serde::{Serialize, Deserialize};
+use std::cmp::Ordering;
+use std::fmt;
+
+/// An exact-arithmetic number backend, backed by `num-rational`'s `BigRational`. Unlike
+/// floating-point arithmetic this never drifts: addition, subtraction, multiplication and division
+/// all stay exact, and integer powers do too. Operations that can't be represented exactly (a
+/// `pow` with a non-integer exponent, or a `sqrt` of a non-perfect-square) return a `MathError`
+/// rather than silently approximating.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Rational(pub BigRational);
+
+impl Rational {
+	pub fn new(val: BigRational) -> Self {
+		Rational(val)
+	}
+}
+
+impl fmt::Display for Rational {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}", self.0)
+	}
+}
+
+impl Num for Rational {
+	/// Note: this captures the exact binary value of `t`, not its decimal literal — `0.1` becomes
+	/// `3602879701896397/36028797018963968`, the closest f64 to one tenth, not `1/10`. A truly
+	/// drift-free decimal literal would need to be parsed to a ratio directly rather than routed
+	/// through `f64` first.
+	fn from_f64(t: f64, _ctx: &Context<Self>) -> Calculation<Self> {
+		match BigRational::from_float(t) {
+			Some(r) => Ok(Answer::Single(Rational(r))),
+			None => Err(MathError::Other {
+				msg: format!("{} cannot be represented exactly as a rational", t),
+			}),
+		}
+	}
+
+	fn add(&self, other: &Self, _ctx: &Context<Self>) -> Calculation<Self> {
+		Ok(Answer::Single(Rational(&self.0 + &other.0)))
+	}
+
+	fn sub(&self, other: &Self, _ctx: &Context<Self>) -> Calculation<Self> {
+		Ok(Answer::Single(Rational(&self.0 - &other.0)))
+	}
+
+	fn mul(&self, other: &Self, _ctx: &Context<Self>) -> Calculation<Self> {
+		Ok(Answer::Single(Rational(&self.0 * &other.0)))
+	}
+
+	fn div(&self, other: &Self, _ctx: &Context<Self>) -> Calculation<Self> {
+		if other.0.is_zero() {
+			return Err(MathError::DivideByZero);
+		}
+		Ok(Answer::Single(Rational(&self.0 / &other.0)))
+	}
+
+	/// Integer exponents stay exact. A non-integer exponent has no general exact rational
+	/// result, so it's reported as an error instead of silently falling back to floats.
+	fn pow(&self, other: &Self, _ctx: &Context<Self>) -> Calculation<Self> {
+		if !other.0.is_integer() {
+			return Err(MathError::Other {
+				msg: String::from("rational backend only supports integer exponents"),
+			});
+		}
+		let exp = match other.0.to_integer().to_i32() {
+			Some(exp) => exp,
+			None => {
+				return Err(MathError::Other {
+					msg: String::from("exponent too large"),
+				})
+			}
+		};
+		if exp >= 0 {
+			Ok(Answer::Single(Rational(self.0.pow(exp))))
+		} else {
+			if self.0.is_zero() {
+				return Err(MathError::DivideByZero);
+			}
+			Ok(Answer::Single(Rational(self.0.pow(exp))))
+		}
+	}
+
+	/// Only exact for perfect squares (numerator and denominator both perfect squares); anything
+	/// else returns a `MathError` rather than an approximate float root.
+	fn sqrt(&self, _ctx: &Context<Self>) -> Calculation<Self> {
+		if self.0.is_negative() {
+			return Err(MathError::NegativeRoot);
+		}
+		match exact_sqrt(&self.0) {
+			Some(root) => Ok(Answer::Single(Rational(root))),
+			None => Err(MathError::Other {
+				msg: format!("{} is not a perfect square, can't be represented exactly", self.0),
+			}),
+		}
+	}
+
+	/// `self - other * floor(self / other)`, exact for any nonzero `other`.
+	fn rem(&self, other: &Self, _ctx: &Context<Self>) -> Calculation<Self> {
+		if other.0.is_zero() {
+			return Err(MathError::DivideByZero);
+		}
+		let quotient = &self.0 / &other.0;
+		let floored = quotient.floor();
+		Ok(Answer::Single(Rational(&self.0 - &other.0 * floored)))
+	}
+
+	fn neg(&self, _ctx: &Context<Self>) -> Calculation<Self> {
+		Ok(Answer::Single(Rational(-&self.0)))
+	}
+
+	fn compare(&self, other: &Self) -> Option<Ordering> {
+		self.0.partial_cmp(&other.0)
+	}
+
+	fn is_truthy(&self) -> bool {
+		!self.0.is_zero()
+	}
+
+	fn from_bool(b: bool, _ctx: &Context<Self>) -> Calculation<Self> {
+		let n = if b { 1 } else { 0 };
+		Ok(Answer::Single(Rational(BigRational::from_integer(BigInt::from(n)))))
+	}
+
+	fn to_f64(&self) -> f64 {
+		self.0.to_f64().unwrap_or(std::f64::NAN)
+	}
+}
+
+/// Tries to take the exact square root of a `BigRational` by taking the integer square root of
+/// numerator and denominator independently and checking the result squares back exactly.
+fn exact_sqrt(val: &BigRational) -> Option<BigRational> {
+	fn isqrt(n: &BigInt) -> BigInt {
+		n.sqrt()
+	}
+
+	let num = val.numer();
+	let den = val.denom();
+	let num_root = isqrt(num);
+	let den_root = isqrt(den);
+	if &(&num_root * &num_root) == num && &(&den_root * &den_root) == den {
+		Some(BigRational::new(num_root, den_root))
+	} else {
+		None
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn r(n: i64, d: i64) -> Rational {
+		Rational(BigRational::new(BigInt::from(n), BigInt::from(d)))
+	}
+
+	#[test]
+	fn rem_matches_floored_division() {
+		let ctx = Context::default();
+		assert_eq!(r(17, 1).rem(&r(5, 1), &ctx).unwrap().unwrap_single(), r(2, 1));
+		assert_eq!(r(17, 2).rem(&r(3, 1), &ctx).unwrap().unwrap_single(), r(5, 2));
+	}
+
+	#[test]
+	fn rem_by_zero_is_an_error() {
+		let ctx = Context::default();
+		assert_eq!(r(1, 1).rem(&r(0, 1), &ctx), Err(MathError::DivideByZero));
+	}
+
+	#[test]
+	fn arithmetic_stays_exact() {
+		let ctx = Context::default();
+		assert_eq!(r(1, 3).add(&r(1, 6), &ctx).unwrap().unwrap_single(), r(1, 2));
+		assert_eq!(r(1, 2).mul(&r(2, 3), &ctx).unwrap().unwrap_single(), r(1, 3));
+		assert_eq!(r(1, 2).div(&r(1, 4), &ctx).unwrap().unwrap_single(), r(2, 1));
+	}
+
+	#[test]
+	fn div_by_zero_is_an_error() {
+		let ctx = Context::default();
+		assert_eq!(r(1, 1).div(&r(0, 1), &ctx), Err(MathError::DivideByZero));
+	}
+
+	#[test]
+	fn pow_integer_exponents_stay_exact() {
+		let ctx = Context::default();
+		assert_eq!(r(2, 1).pow(&r(3, 1), &ctx).unwrap().unwrap_single(), r(8, 1));
+		assert_eq!(r(2, 1).pow(&r(-1, 1), &ctx).unwrap().unwrap_single(), r(1, 2));
+	}
+
+	#[test]
+	fn pow_non_integer_exponent_is_an_error() {
+		let ctx = Context::default();
+		assert!(r(2, 1).pow(&r(1, 2), &ctx).is_err());
+	}
+
+	#[test]
+	fn sqrt_perfect_square_is_exact() {
+		let ctx = Context::default();
+		assert_eq!(r(9, 4).sqrt(&ctx).unwrap().unwrap_single(), r(3, 2));
+	}
+
+	#[test]
+	fn sqrt_non_perfect_square_is_an_error() {
+		let ctx = Context::default();
+		assert!(r(2, 1).sqrt(&ctx).is_err());
+	}
+
+	#[test]
+	fn from_f64_captures_binary_value_not_decimal() {
+		let ctx = Context::default();
+		let tenth = Rational::from_f64(0.1, &ctx).unwrap().unwrap_single();
+		assert_ne!(tenth, r(1, 10));
+	}
+}
@@ -1,31 +1,59 @@
 use serde::{Serialize, Deserialize};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub(crate) enum Op {
+pub enum Op {
 	In(In),
 	Pre(Pre),
 	Post(Post),
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub(crate) enum In {
+pub enum In {
+	/// `N d M` ("N d-six", "3d6") rolls `N` `M`-sided dice and produces an `Answer::Distribution`
+	/// of outcomes over probabilities, rather than a single `Num`. See `Answer::dice` (a bare
+	/// `dM` is `Answer::die`, i.e. `1 d M`).
+	Dice,
 	Pow,
 	Mul,
 	Div,
+	/// Remainder, written `mod` (evaluated as `a - b * floor(a / b)` via `Num::rem`). A bare `%`
+	/// is ambiguous with the postfix `Post::Percent`; the two are only distinguishable by whether
+	/// a right-hand operand follows the `%`, which is a tokenizing concern. This crate doesn't
+	/// ship a tokenizer/parser of its own (there's no lexer anywhere in this tree) — disambiguating
+	/// `%` is left entirely to whatever tokenizer a caller puts in front of `Op`; it should only
+	/// emit `In::Mod` when a value follows the `%`, and `Post::Percent` otherwise.
+	Mod,
 	Add,
 	Sub,
 	PlusMinus,
+
+	/// Comparisons and the logical connectives below evaluate to `0` or `1` in the numeric
+	/// domain, since `Num` has no boolean type of its own.
+	Lt,
+	Le,
+	Gt,
+	Ge,
+	Eq,
+	Ne,
+
+	And,
+	Or,
+	/// `Implies(a, b)` is evaluated as `Or(Not(a), b)`.
+	Implies,
+	/// `Iff(a, b)` is evaluated as equality of the two truth values.
+	Iff,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub(crate) enum Pre {
+pub enum Pre {
 	Neg,
 	Pos,
 	PosNeg,
+	Not,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub(crate) enum Post {
+pub enum Post {
 	Fact,
 	Percent,
 }
@@ -37,12 +65,15 @@ impl Op {
 		use self::Post::*;
 		match *self {
 			Op::In(ref op) => match *op {
+				Dice => 5,
 				Pow => 4,
-				Mul | Div => 3,
+				Mul | Div | Mod => 3,
 				Add | Sub | PlusMinus => 2,
+				Lt | Le | Gt | Ge | Eq | Ne => 1,
+				And | Or | Implies | Iff => 0,
 			},
 			Op::Pre(ref op) => match *op {
-				Neg | Pos | PosNeg => 4,
+				Neg | Pos | PosNeg | Not => 4,
 			},
 			Op::Post(ref op) => match *op {
 				Fact => 4,
@@ -57,11 +88,14 @@ impl Op {
 		use self::Post::*;
 		match *self {
 			Op::In(ref op) => match *op {
+				Dice => true,
 				Pow => false,
-				Mul | Div | Add | Sub | PlusMinus => true,
+				Mul | Div | Mod | Add | Sub | PlusMinus => true,
+				Lt | Le | Gt | Ge | Eq | Ne | And | Or => true,
+				Implies | Iff => false,
 			},
 			Op::Pre(ref op) => match *op {
-				Neg | Pos | PosNeg => false,
+				Neg | Pos | PosNeg | Not => false,
 			},
 			Op::Post(ref op) => match *op {
 				Fact => true,
@@ -76,17 +110,30 @@ impl Op {
 		use self::Post::*;
 		String::from(match *self {
 			Op::In(ref op) => match *op {
+				Dice => "d",
 				Pow => "^",
 				Mul => "*",
 				Div => "/",
+				Mod => "mod",
 				Add => "+",
 				Sub => "-",
 				PlusMinus => "±",
+				Lt => "<",
+				Le => "<=",
+				Gt => ">",
+				Ge => ">=",
+				Eq => "=",
+				Ne => "!=",
+				And => "and",
+				Or => "or",
+				Implies => "implies",
+				Iff => "iff",
 			},
 			Op::Pre(ref op) => match *op {
 				Neg => "-",
 				Pos => "+",
 				PosNeg => "±",
+				Not => "not",
 			},
 			Op::Post(ref op) => match *op {
 				Fact => "!",
@@ -112,6 +159,73 @@ impl fmt::Display for Op {
 	}
 }
 
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn mod_precedence_and_display() {
+		let op = Op::In(In::Mod);
+		assert_eq!(op.precedence(), Op::In(In::Mul).precedence());
+		assert_eq!(op.precedence(), Op::In(In::Div).precedence());
+		assert!(op.is_left_associative());
+		assert_eq!(op.to_string(), "mod");
+	}
+
+	#[test]
+	fn dice_precedence_and_display() {
+		let op = Op::In(In::Dice);
+		assert!(op.precedence() > Op::In(In::Pow).precedence());
+		assert!(op.is_left_associative());
+		assert_eq!(op.to_string(), "d");
+	}
+
+	#[test]
+	fn comparison_and_logical_tiers_are_ordered() {
+		let comparisons = [In::Lt, In::Le, In::Gt, In::Ge, In::Eq, In::Ne];
+		let connectives = [In::And, In::Or, In::Implies, In::Iff];
+		let arithmetic_precedence = Op::In(In::Add).precedence();
+
+		for op in &comparisons {
+			assert!(Op::In(op.clone()).precedence() < arithmetic_precedence);
+		}
+		for op in &connectives {
+			assert!(Op::In(op.clone()).precedence() < Op::In(In::Lt).precedence());
+		}
+
+		// Within a tier, every member shares the same precedence.
+		for op in &comparisons {
+			assert_eq!(Op::In(op.clone()).precedence(), Op::In(In::Lt).precedence());
+		}
+		for op in &connectives {
+			assert_eq!(Op::In(op.clone()).precedence(), Op::In(In::And).precedence());
+		}
+	}
+
+	#[test]
+	fn implies_and_iff_are_right_associative() {
+		assert!(!Op::In(In::Implies).is_left_associative());
+		assert!(!Op::In(In::Iff).is_left_associative());
+		assert!(Op::In(In::And).is_left_associative());
+		assert!(Op::In(In::Or).is_left_associative());
+	}
+
+	#[test]
+	fn comparison_and_logical_display() {
+		assert_eq!(Op::In(In::Lt).to_string(), "<");
+		assert_eq!(Op::In(In::Le).to_string(), "<=");
+		assert_eq!(Op::In(In::Gt).to_string(), ">");
+		assert_eq!(Op::In(In::Ge).to_string(), ">=");
+		assert_eq!(Op::In(In::Eq).to_string(), "=");
+		assert_eq!(Op::In(In::Ne).to_string(), "!=");
+		assert_eq!(Op::In(In::And).to_string(), "and");
+		assert_eq!(Op::In(In::Or).to_string(), "or");
+		assert_eq!(Op::In(In::Implies).to_string(), "implies");
+		assert_eq!(Op::In(In::Iff).to_string(), "iff");
+		assert_eq!(Op::Pre(Pre::Not).to_string(), "not");
+	}
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) enum Paren {
 	Open,
@@ -0,0 +1,7 @@
+use crate::answer::Answer;
+use crate::errors::MathError;
+use crate::num::Num;
+
+/// The result of evaluating (a piece of) an expression: either a successful `Answer`, or a
+/// `MathError` if evaluation failed (division by zero, non-exact root, and so on).
+pub type Calculation<N> = Result<Answer<N>, MathError>;